@@ -0,0 +1,193 @@
+use std::path::{Path as FsPath, PathBuf};
+use std::sync::Arc;
+
+use arc_swap::ArcSwap;
+use notify::{RecommendedWatcher, RecursiveMode, Watcher};
+use oas3::spec::Operation;
+use oas3::{self, Spec};
+
+use crate::components::composite_objects::{Path, RequestObject, ResponseObject};
+
+// Built once at startup and injected into every request instead of being
+// reparsed per-request; `routes` lives behind an `ArcSwap` so `watch` can hot-swap it.
+pub struct AppState {
+    pub routes: ArcSwap<Vec<Path>>,
+}
+
+impl AppState {
+    pub async fn new(
+        openapi_path: &str,
+    ) -> Result<AppState, Box<dyn std::error::Error + Send + Sync>> {
+        let spec = oas3::from_path(openapi_path)?;
+        let routes = get_paths_from_spec(&spec).await;
+
+        Ok(AppState {
+            routes: ArcSwap::from_pointee(routes),
+        })
+    }
+
+    pub async fn reload(
+        &self,
+        openapi_path: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let spec = oas3::from_path(openapi_path)?;
+        let routes = get_paths_from_spec(&spec).await;
+
+        self.routes.store(Arc::new(routes));
+        Ok(())
+    }
+
+    // Reloads the spec whenever `openapi_path` changes, giving hot reload without a restart.
+    pub fn watch(self: Arc<Self>, openapi_path: String) {
+        let handle = tokio::runtime::Handle::current();
+
+        tokio::task::spawn_blocking(move || {
+            let state = self;
+            let target = FsPath::new(&openapi_path);
+            let file_name = match target.file_name() {
+                Some(name) => name.to_os_string(),
+                None => {
+                    eprintln!("Cannot watch {}: no file name", openapi_path);
+                    return;
+                }
+            };
+            // Watch the parent directory, not the file itself: a rename (the
+            // write-temp-then-rename editors and deploy tools use) unlinks the
+            // file's inode, which would silently kill a watch placed on it directly.
+            let dir = match target.parent().filter(|p| !p.as_os_str().is_empty()) {
+                Some(dir) => dir.to_path_buf(),
+                None => PathBuf::from("."),
+            };
+
+            let (tx, rx) = std::sync::mpsc::channel();
+            let mut watcher = match RecommendedWatcher::new(tx, notify::Config::default()) {
+                Ok(watcher) => watcher,
+                Err(err) => {
+                    eprintln!("Failed to start watcher for {}: {}", openapi_path, err);
+                    return;
+                }
+            };
+
+            if let Err(err) = watcher.watch(&dir, RecursiveMode::NonRecursive) {
+                eprintln!("Failed to watch {}: {}", dir.display(), err);
+                return;
+            }
+
+            // Blocks the thread on the channel; notify delivers events from its own thread.
+            for res in rx {
+                let event = match res {
+                    Ok(event) => event,
+                    Err(err) => {
+                        eprintln!("Error watching {}: {}", openapi_path, err);
+                        continue;
+                    }
+                };
+
+                let is_target = event.paths.iter().any(|p| p.file_name() == Some(&file_name));
+                let is_change =
+                    event.kind.is_modify() || event.kind.is_create() || event.kind.is_remove();
+                if !is_target || !is_change {
+                    continue;
+                }
+
+                let state = state.clone();
+                let path = openapi_path.clone();
+                handle.spawn(async move {
+                    match state.reload(&path).await {
+                        Ok(()) => println!("Reloaded {} after change", path),
+                        Err(err) => eprintln!("Failed to reload {}: {}", path, err),
+                    }
+                });
+            }
+        });
+    }
+}
+
+async fn get_response_object_schema_by_operation(
+    spec: &Spec,
+    operation: &Operation,
+) -> Option<ResponseObject> {
+    let response = operation.responses(spec);
+    let content = response.get("200");
+
+    let object_schema = match content {
+        Some(a) => a
+            .content
+            .get("application/json")?
+            .schema
+            .as_ref()?
+            .resolve(spec)
+            .ok(),
+        None => None,
+    };
+    let response_object =
+        ResponseObject::create_response_object_by_object_schema(spec, &object_schema);
+    Some(response_object)
+}
+
+async fn get_request_object_schema_by_operation(
+    spec: &Spec,
+    operation: &Operation,
+) -> Option<RequestObject> {
+    let requests = match operation.clone().request_body {
+        Some(rb) => rb.resolve(spec),
+        None => return None,
+    };
+
+    let object_schema = match requests {
+        Ok(a) => a
+            .content
+            .get("application/json")?
+            .schema
+            .as_ref()?
+            .resolve(spec)
+            .ok(),
+        Err(_e) => None,
+    };
+
+    let request_object =
+        RequestObject::create_request_object_by_object_schema(spec, &object_schema);
+    Some(request_object)
+}
+
+async fn get_paths_from_spec(spec: &Spec) -> Vec<Path> {
+    let path = match spec.paths.as_ref() {
+        Some(p) => p,
+        None => return vec![], // Return an empty vector if paths are None
+    };
+
+    let mut paths: Vec<Path> = vec![];
+
+    // Iterate over paths using iter() to avoid ownership issues
+    for (path_str, path_item) in path.iter() {
+        if let Some(get) = path_item.get.as_ref() {
+            // Call the asynchronous functions using `await`
+            let response_object = get_response_object_schema_by_operation(spec, get).await;
+            let request_object = get_request_object_schema_by_operation(spec, get).await;
+
+            // Push the new Path into the vector
+            paths.push(Path {
+                path: path_str.clone(),
+                method: hyper::Method::GET,
+                response_object,
+                request_object,
+            });
+        }
+
+        if let Some(post) = path_item.post.as_ref() {
+            // Call the asynchronous functions using `await`
+            let response_object = get_response_object_schema_by_operation(spec, post).await;
+            let request_object = get_request_object_schema_by_operation(spec, post).await;
+
+            // Push the new Path into the vector
+            paths.push(Path {
+                path: path_str.clone(),
+                method: hyper::Method::POST,
+                response_object,
+                request_object,
+            });
+        }
+    }
+
+    paths // Return the vector
+}